@@ -0,0 +1,1107 @@
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashSet};
+
+use clap::{Parser, ValueEnum};
+use serde::Serialize;
+use tokio::fs;
+use tokio::io::{self, AsyncBufReadExt, BufReader};
+
+/// Weld offcuts together to build the requested beam lengths.
+#[derive(Parser)]
+#[command(version, about)]
+struct Cli {
+    /// Beam requirements file
+    requirements: String,
+    /// Offcuts file
+    offcuts: String,
+    /// Output format
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+    /// Strategy used to assign offcuts across beams
+    #[arg(long, value_enum, default_value_t = Strategy::PerBeam)]
+    strategy: Strategy,
+    /// Order to process beam requirements in
+    #[arg(long, value_enum, default_value_t = SortBeams::Desc)]
+    sort_beams: SortBeams,
+    /// Material lost to each cut, in mm. Charged once per weld, alongside
+    /// `--weld-gap`.
+    #[arg(long, default_value_t = 0)]
+    kerf: i64,
+    /// Material lost or gained at each weld joint, in mm. Negative means
+    /// welding adds filler rather than consuming length.
+    #[arg(long, default_value_t = 0)]
+    weld_gap: i64,
+}
+
+/// How to render the solved beams.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Format {
+    Text,
+    Markdown,
+    Json,
+}
+
+/// How to assign offcuts across beams.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum Strategy {
+    /// One-pass per beam: take the smallest single offcut that fits, or
+    /// else keep taking the largest remaining offcut until the target is
+    /// reached, with no backtracking. Fast, but can pick a wasteful
+    /// combination - or miss one entirely - that a more exhaustive strategy
+    /// would find.
+    Greedy,
+    /// Solve each beam independently, taking the lowest-waste Pareto point.
+    /// Aliased as `exact`, its name before the two binaries were unified.
+    #[value(alias = "exact")]
+    PerBeam,
+    /// Jointly assign offcuts across all beams: first maximize the number
+    /// solved, then minimize total waste.
+    Global,
+}
+
+/// Order to sort beam requirements into before solving.
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum SortBeams {
+    Asc,
+    Desc,
+}
+
+/// Represents a completed beam solution.
+#[derive(Debug, Clone, Serialize)]
+struct BeamPlan {
+    total: usize,
+    welds: usize,
+    used_offcuts: Vec<usize>,
+    waste: usize,
+}
+
+/// Represents a beam requirement specification.
+#[derive(Debug, Clone)]
+struct BeamRequires {
+    size: usize,
+    welds: Vec<usize>,
+}
+
+/// Material lost (or, if negative, gained) at every weld joint: the kerf
+/// removed cutting the joint plus any gap allowance the weld itself
+/// consumes. Charged once per weld, so `k` pieces lose `per_weld() * (k-1)`
+/// off their raw summed length.
+#[derive(Debug, Clone, Copy)]
+struct WeldLoss {
+    kerf: i64,
+    weld_gap: i64,
+}
+
+impl WeldLoss {
+    fn per_weld(&self) -> i64 {
+        self.kerf + self.weld_gap
+    }
+}
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    // Load files concurrently
+    let (mut requirements, offcuts) = tokio::try_join!(
+        load_beam_requirements(&cli.requirements),
+        load_offcuts(&cli.offcuts)
+    )?;
+
+    match cli.sort_beams {
+        SortBeams::Desc => requirements.sort_unstable_by_key(|b| Reverse(b.size)),
+        SortBeams::Asc => requirements.sort_unstable_by_key(|b| b.size),
+    }
+
+    let weld_loss = WeldLoss {
+        kerf: cli.kerf,
+        weld_gap: cli.weld_gap,
+    };
+
+    let report = match cli.strategy {
+        Strategy::Greedy => solve_greedy(requirements, offcuts, weld_loss),
+        Strategy::PerBeam => solve_per_beam(requirements, offcuts, weld_loss),
+        Strategy::Global => solve_global_report(requirements, offcuts, weld_loss),
+    };
+
+    match cli.format {
+        Format::Text => print_text(&report),
+        Format::Markdown => print_markdown(&report),
+        Format::Json => print_json(&report)?,
+    }
+
+    Ok(())
+}
+
+/// Load beam requirements from file asynchronously
+async fn load_beam_requirements(path: &str) -> io::Result<Vec<BeamRequires>> {
+    let file = fs::File::open(path).await?;
+    let reader = BufReader::new(file);
+    let mut lines = reader.lines();
+    let mut requirements = Vec::new();
+
+    while let Some(line) = lines.next_line().await? {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+
+        let numbers: Vec<usize> = line
+            .split_whitespace()
+            .filter_map(|s| s.parse::<usize>().ok())
+            .collect();
+
+        if let Some((&size, welds)) = numbers.split_first() {
+            // A zero-length beam needs no pieces, which breaks the
+            // `pieces_used - 1` weld-count accounting in the solvers below;
+            // treat it as malformed input, same as an unparseable line.
+            if size > 0 {
+                requirements.push(BeamRequires {
+                    size,
+                    welds: welds.to_vec(),
+                });
+            }
+        }
+    }
+
+    Ok(requirements)
+}
+
+/// Load offcuts from file asynchronously
+async fn load_offcuts(path: &str) -> io::Result<Vec<usize>> {
+    let content = fs::read_to_string(path).await?;
+    let mut offcuts: Vec<usize> = content
+        .split_whitespace()
+        .filter_map(|s| s.parse::<usize>().ok())
+        .collect();
+
+    // Sort descending: the solvers' feasibility prunes assume this order.
+    offcuts.sort_unstable_by(|a, b| b.cmp(a));
+    Ok(offcuts)
+}
+
+/// The solved outcome for a single beam requirement.
+struct BeamResult {
+    requirement: BeamRequires,
+    /// The full weld/waste Pareto front, if the strategy computed one
+    /// (per-beam); empty for strategies that only produce a single plan.
+    front: Vec<BeamPlan>,
+    /// The plan actually built and consumed from the offcut pool.
+    chosen: Option<BeamPlan>,
+}
+
+/// The full result of solving a set of beam requirements against a pool of
+/// offcuts, independent of how it's rendered.
+struct Report {
+    beams: Vec<BeamResult>,
+    initial_offcuts: usize,
+    initial_material: usize,
+    remaining_offcuts: Vec<usize>,
+}
+
+/// Solve each beam independently in requirement order via a one-pass greedy
+/// pick, consuming offcuts as it goes. No backtracking: an early beam can
+/// take a piece a later beam would have needed more.
+fn solve_greedy(
+    requirements: Vec<BeamRequires>,
+    mut offcuts: Vec<usize>,
+    weld_loss: WeldLoss,
+) -> Report {
+    let initial_offcuts = offcuts.len();
+    let initial_material: usize = offcuts.iter().sum();
+    let mut beams = Vec::with_capacity(requirements.len());
+
+    for requirement in requirements {
+        let max_cap = requirement.welds.iter().copied().max().unwrap_or(0);
+        let chosen = greedy_plan(&offcuts, requirement.size, max_cap, weld_loss);
+
+        if let Some(plan) = &chosen {
+            for &offcut in &plan.used_offcuts {
+                let pos = offcuts
+                    .iter()
+                    .position(|&o| o == offcut)
+                    .expect("chosen offcut must still be in the pool");
+                offcuts.remove(pos);
+            }
+        }
+
+        beams.push(BeamResult {
+            requirement,
+            front: Vec::new(),
+            chosen,
+        });
+    }
+
+    Report {
+        beams,
+        initial_offcuts,
+        initial_material,
+        remaining_offcuts: offcuts,
+    }
+}
+
+/// One-pass greedy pick for a single beam: the single smallest offcut that
+/// fits (0 waste beyond that piece's own slack), or else the largest
+/// remaining offcut repeatedly until `target_length` is reached or the weld
+/// budget runs out. No backtracking, so it can pick a wasteful combination
+/// - or miss a feasible one - that an exhaustive search would find.
+fn greedy_plan(
+    offcuts: &[usize],
+    target_length: usize,
+    max_welds: usize,
+    weld_loss: WeldLoss,
+) -> Option<BeamPlan> {
+    let target = target_length as i64;
+
+    // Offcuts are sorted descending, so the last one that still fits is the
+    // smallest single piece reaching the target.
+    if let Some(&best_fit) = offcuts.iter().rev().find(|&&o| o as i64 >= target) {
+        return Some(BeamPlan {
+            total: best_fit,
+            welds: 0,
+            used_offcuts: vec![best_fit],
+            waste: best_fit - target_length,
+        });
+    }
+
+    let max_pieces = max_welds + 1;
+    let mut used_offcuts = Vec::new();
+    let mut total: i64 = 0;
+    for &offcut in offcuts {
+        if used_offcuts.len() >= max_pieces {
+            break;
+        }
+        total += offcut as i64
+            - if used_offcuts.is_empty() {
+                0
+            } else {
+                weld_loss.per_weld()
+            };
+        used_offcuts.push(offcut);
+        if total >= target {
+            break;
+        }
+    }
+
+    if total >= target {
+        Some(BeamPlan {
+            total: total as usize,
+            welds: used_offcuts.len() - 1,
+            used_offcuts,
+            waste: (total - target) as usize,
+        })
+    } else {
+        None
+    }
+}
+
+/// Solve each beam independently in requirement order, taking the
+/// lowest-waste point on its weld/waste Pareto front.
+fn solve_per_beam(
+    requirements: Vec<BeamRequires>,
+    mut offcuts: Vec<usize>,
+    weld_loss: WeldLoss,
+) -> Report {
+    let initial_offcuts = offcuts.len();
+    let initial_material: usize = offcuts.iter().sum();
+    let mut beams = Vec::with_capacity(requirements.len());
+
+    for requirement in requirements {
+        let max_cap = requirement.welds.iter().copied().max().unwrap_or(0);
+        let front = pareto_front(&offcuts, requirement.size, max_cap, weld_loss);
+        let chosen = front.iter().min_by_key(|p| (p.waste, p.welds)).cloned();
+
+        if let Some(plan) = &chosen {
+            for &offcut in &plan.used_offcuts {
+                let pos = offcuts
+                    .iter()
+                    .position(|&o| o == offcut)
+                    .expect("chosen offcut must still be in the pool");
+                offcuts.remove(pos);
+            }
+        }
+
+        beams.push(BeamResult {
+            requirement,
+            front,
+            chosen,
+        });
+    }
+
+    Report {
+        beams,
+        initial_offcuts,
+        initial_material,
+        remaining_offcuts: offcuts,
+    }
+}
+
+/// Solve all beams jointly via the global optimizer.
+fn solve_global_report(
+    requirements: Vec<BeamRequires>,
+    offcuts: Vec<usize>,
+    weld_loss: WeldLoss,
+) -> Report {
+    let initial_offcuts = offcuts.len();
+    let initial_material: usize = offcuts.iter().sum();
+    let solution = solve_global(&requirements, offcuts, weld_loss);
+
+    let beams = requirements
+        .into_iter()
+        .zip(solution.plans)
+        .map(|(requirement, chosen)| BeamResult {
+            requirement,
+            front: Vec::new(),
+            chosen,
+        })
+        .collect();
+
+    Report {
+        beams,
+        initial_offcuts,
+        initial_material,
+        remaining_offcuts: solution.leftover,
+    }
+}
+
+/// Render the report as plain text lines.
+fn print_text(report: &Report) {
+    for (idx, beam) in report.beams.iter().enumerate() {
+        println!("{} mm", beam.requirement.size);
+
+        if beam.front.is_empty() {
+            match &beam.chosen {
+                Some(plan) => println!(
+                    "{} mm, {} weld: BeamPlan {{ total: {}, welds: {}, used_offcuts: {:?}, waste: {} }}",
+                    beam.requirement.size, plan.welds, plan.total, plan.welds, plan.used_offcuts, plan.waste
+                ),
+                None => println!("{} mm - not found", beam.requirement.size),
+            }
+        } else {
+            for plan in &beam.front {
+                println!(
+                    "{} mm, {} weld -> waste {} mm",
+                    beam.requirement.size, plan.welds, plan.waste
+                );
+            }
+
+            match &beam.chosen {
+                Some(plan) => println!(
+                    "  -> chosen: {} weld, waste {} mm, used_offcuts: {:?}",
+                    plan.welds, plan.waste, plan.used_offcuts
+                ),
+                None => println!("  -> not found"),
+            }
+        }
+
+        if idx < report.beams.len() - 1 {
+            println!();
+        }
+    }
+
+    println!();
+    println!(
+        "remaining offcuts ({}): {:?}",
+        report.remaining_offcuts.len(),
+        report.remaining_offcuts
+    );
+}
+
+/// Render the report as a markdown document with summary statistics.
+fn print_markdown(report: &Report) {
+    let total_beams = report.beams.len();
+
+    println!("# Beam Welding Solutions\n");
+    println!("## Input Summary\n");
+    println!("- **Total beams required**: {}", total_beams);
+    println!("- **Available offcuts**: {}", report.initial_offcuts);
+    println!("- **Total material**: {} mm\n", report.initial_material);
+
+    let mut solved_count = 0;
+    let mut total_waste = 0;
+
+    for (idx, beam) in report.beams.iter().enumerate() {
+        println!("## Beam {} - {} mm\n", idx + 1, beam.requirement.size);
+
+        if !beam.front.is_empty() {
+            println!("| Welds | Waste (mm) | Actual length (mm) |");
+            println!("|---|---|---|");
+            for plan in &beam.front {
+                println!("| {} | {} | {} |", plan.welds, plan.waste, plan.total);
+            }
+            println!();
+        }
+
+        match &beam.chosen {
+            Some(plan) => {
+                solved_count += 1;
+                total_waste += plan.waste;
+                print_solution_markdown(plan);
+            }
+            None => println!("❌ **No solution found**\n"),
+        }
+
+        if idx < total_beams - 1 {
+            println!("---\n");
+        }
+    }
+
+    println!("\n## Summary\n");
+    println!("- **Beams solved**: {}/{}", solved_count, total_beams);
+    println!("- **Remaining offcuts**: {}", report.remaining_offcuts.len());
+    println!("- **Total waste**: {} mm", total_waste);
+
+    let remaining_material: usize = report.remaining_offcuts.iter().sum();
+    println!("- **Remaining material**: {} mm", remaining_material);
+    if report.initial_material > 0 {
+        println!(
+            "- **Material efficiency**: {:.1}%",
+            ((report.initial_material - remaining_material) as f64 / report.initial_material as f64) * 100.0
+        );
+    }
+}
+
+/// Print a single beam's chosen plan in markdown format.
+fn print_solution_markdown(plan: &BeamPlan) {
+    println!(
+        "✅ **{} weld{}** - Solution found",
+        plan.welds,
+        if plan.welds == 1 { "" } else { "s" }
+    );
+    println!("- **Actual length**: {} mm", plan.total);
+    println!("- **Welds used**: {}", plan.welds);
+    println!(
+        "- **Waste**: {} mm ({:.1}%)",
+        plan.waste,
+        (plan.waste as f64 / plan.total as f64) * 100.0
+    );
+
+    print!("- **Offcuts used**: ");
+    for (i, &offcut) in plan.used_offcuts.iter().enumerate() {
+        if i > 0 {
+            print!(" + ");
+        }
+        print!("{} mm", offcut);
+    }
+    println!(" = {} mm\n", plan.used_offcuts.iter().sum::<usize>());
+}
+
+/// A single beam's entry in the JSON report.
+#[derive(Serialize)]
+struct BeamJson {
+    size: usize,
+    requested_weld_caps: Vec<usize>,
+    plan: Option<BeamPlan>,
+}
+
+/// Top-level summary statistics in the JSON report.
+#[derive(Serialize)]
+struct SummaryJson {
+    beams_total: usize,
+    beams_solved: usize,
+    total_waste: usize,
+    remaining_offcuts: usize,
+    remaining_material: usize,
+    efficiency_percent: f64,
+}
+
+/// The JSON document emitted by `--format json`.
+#[derive(Serialize)]
+struct ReportJson {
+    beams: Vec<BeamJson>,
+    summary: SummaryJson,
+}
+
+/// Render the report as a structured JSON document for downstream tools.
+fn print_json(report: &Report) -> Result<(), Box<dyn std::error::Error>> {
+    let beams_solved = report.beams.iter().filter(|b| b.chosen.is_some()).count();
+    let total_waste: usize = report
+        .beams
+        .iter()
+        .filter_map(|b| b.chosen.as_ref())
+        .map(|plan| plan.waste)
+        .sum();
+    let remaining_material: usize = report.remaining_offcuts.iter().sum();
+    let efficiency_percent = if report.initial_material == 0 {
+        0.0
+    } else {
+        ((report.initial_material - remaining_material) as f64 / report.initial_material as f64) * 100.0
+    };
+
+    let doc = ReportJson {
+        beams: report
+            .beams
+            .iter()
+            .map(|b| BeamJson {
+                size: b.requirement.size,
+                requested_weld_caps: b.requirement.welds.clone(),
+                plan: b.chosen.clone(),
+            })
+            .collect(),
+        summary: SummaryJson {
+            beams_total: report.beams.len(),
+            beams_solved,
+            total_waste,
+            remaining_offcuts: report.remaining_offcuts.len(),
+            remaining_material,
+            efficiency_percent,
+        },
+    };
+
+    println!("{}", serde_json::to_string_pretty(&doc)?);
+    Ok(())
+}
+
+/// The outcome of `solve_global`: the chosen plan per beam (in requirement
+/// order, `None` if left unsolved) and the offcuts left over afterwards.
+struct GlobalSolution {
+    plans: Vec<Option<BeamPlan>>,
+    leftover: Vec<usize>,
+}
+
+/// Assign offcuts across all beams jointly via branch-and-bound, first
+/// maximizing the number of beams solved, then minimizing total waste.
+///
+/// At each beam we branch on every candidate combination (a handful of
+/// near-optimal ones from `candidate_combinations`) plus the option to
+/// leave the beam unsolved, then recurse with the remaining offcuts. A
+/// `(solved_count, total_waste)` incumbent prunes branches that can no
+/// longer beat it, tightened by a bin-packing upper bound on how many of
+/// the remaining beams the remaining material could possibly satisfy.
+fn solve_global(
+    requirements: &[BeamRequires],
+    offcuts: Vec<usize>,
+    weld_loss: WeldLoss,
+) -> GlobalSolution {
+    let n = requirements.len();
+    let mut best_solved = 0usize;
+    let mut best_waste = usize::MAX;
+    let mut best_plans: Vec<Option<BeamPlan>> = vec![None; n];
+    let mut best_leftover: Vec<usize> = offcuts.clone();
+    let mut plans: Vec<Option<BeamPlan>> = vec![None; n];
+    let mut offcuts = offcuts;
+
+    recurse_global(
+        requirements,
+        0,
+        &mut offcuts,
+        weld_loss,
+        0,
+        0,
+        &mut plans,
+        &mut best_solved,
+        &mut best_waste,
+        &mut best_plans,
+        &mut best_leftover,
+    );
+
+    GlobalSolution {
+        plans: best_plans,
+        leftover: best_leftover,
+    }
+}
+
+/// Upper bound on how many of `requirements[idx..]` could be satisfied from
+/// `material` mm of offcuts, found by greedily counting the cheapest beams
+/// first. Ignores offcut granularity, so it never undercounts what's
+/// actually achievable: each beam's demand is its minimum possible raw
+/// material requirement — `size` pieces together when welding only costs
+/// length, or `size` reduced by the maximum gain its weld budget could add
+/// when welding adds filler (`per_weld() < 0`) — so it's never pruned out
+/// as infeasible when it could in fact be built.
+fn max_beams_satisfiable(
+    requirements: &[BeamRequires],
+    idx: usize,
+    material: usize,
+    weld_loss: WeldLoss,
+) -> usize {
+    let max_gain_per_weld = (-weld_loss.per_weld()).max(0);
+    let mut demands: Vec<i64> = requirements[idx..]
+        .iter()
+        .map(|b| {
+            let max_welds = b.welds.iter().copied().max().unwrap_or(0) as i64;
+            (b.size as i64 - max_gain_per_weld * max_welds).max(0)
+        })
+        .collect();
+    demands.sort_unstable();
+
+    let material = material as i64;
+    let mut used = 0i64;
+    let mut count = 0;
+    for demand in demands.drain(..) {
+        if used + demand > material {
+            break;
+        }
+        used += demand;
+        count += 1;
+    }
+    count
+}
+
+/// Branch-and-bound step for `solve_global`. See that function for the
+/// overall strategy.
+#[allow(clippy::too_many_arguments)]
+fn recurse_global(
+    requirements: &[BeamRequires],
+    idx: usize,
+    offcuts: &mut Vec<usize>,
+    weld_loss: WeldLoss,
+    solved: usize,
+    waste: usize,
+    plans: &mut Vec<Option<BeamPlan>>,
+    best_solved: &mut usize,
+    best_waste: &mut usize,
+    best_plans: &mut Vec<Option<BeamPlan>>,
+    best_leftover: &mut Vec<usize>,
+) {
+    if idx == requirements.len() {
+        if solved > *best_solved || (solved == *best_solved && waste < *best_waste) {
+            *best_solved = solved;
+            *best_waste = waste;
+            *best_plans = plans.clone();
+            *best_leftover = offcuts.clone();
+        }
+        return;
+    }
+
+    let material: usize = offcuts.iter().sum();
+    let max_additional = max_beams_satisfiable(requirements, idx, material, weld_loss);
+    if solved + max_additional < *best_solved {
+        return;
+    }
+    if solved + max_additional == *best_solved && waste >= *best_waste {
+        return;
+    }
+
+    let beam = &requirements[idx];
+    let max_welds = beam.welds.iter().copied().max().unwrap_or(0);
+    let candidates =
+        candidate_combinations(offcuts, beam.size, max_welds, weld_loss, CANDIDATES_PER_BEAM);
+
+    for (total, indices) in &candidates {
+        let mut sorted_indices = indices.clone();
+        sorted_indices.sort_unstable_by(|a, b| b.cmp(a));
+        let mut removed: Vec<usize> = sorted_indices.iter().map(|&i| offcuts.remove(i)).collect();
+        removed.reverse();
+
+        // `total` is the kerf/weld-gap adjusted assembled length; guaranteed
+        // >= beam.size since that's what made it a candidate.
+        let total = *total as usize;
+        let plan = BeamPlan {
+            total,
+            welds: removed.len() - 1,
+            used_offcuts: removed.clone(),
+            waste: total - beam.size,
+        };
+        plans[idx] = Some(plan.clone());
+
+        recurse_global(
+            requirements,
+            idx + 1,
+            offcuts,
+            weld_loss,
+            solved + 1,
+            waste + plan.waste,
+            plans,
+            best_solved,
+            best_waste,
+            best_plans,
+            best_leftover,
+        );
+
+        plans[idx] = None;
+        for &value in removed.iter().rev() {
+            let pos = offcuts.partition_point(|&x| x > value);
+            offcuts.insert(pos, value);
+        }
+    }
+
+    // Option: leave this beam unsolved and move on.
+    recurse_global(
+        requirements,
+        idx + 1,
+        offcuts,
+        weld_loss,
+        solved,
+        waste,
+        plans,
+        best_solved,
+        best_waste,
+        best_plans,
+        best_leftover,
+    );
+}
+
+/// The constant used by `solve_global` to cap how many near-optimal
+/// combinations are explored per beam. This is a heuristic: a beam's true
+/// optimum might not be among the top `CANDIDATES_PER_BEAM` by its own
+/// total, if a wastier-looking combination frees offcuts that solve more
+/// beams overall. So `solve_global`'s result is the best assignment
+/// reachable through this narrowed branching, not a proven global optimum.
+const CANDIDATES_PER_BEAM: usize = 3;
+
+/// Find up to `limit` distinct feasible combinations of at most
+/// `max_welds + 1` offcuts whose kerf/weld-gap adjusted length reaches
+/// `target_length`, in ascending order of that adjusted total. Used by the
+/// global optimizer, which needs several near-optimal choices per beam
+/// rather than just the single best one.
+fn candidate_combinations(
+    offcuts: &[usize],
+    target_length: usize,
+    max_welds: usize,
+    weld_loss: WeldLoss,
+    limit: usize,
+) -> Vec<(i64, Vec<usize>)> {
+    let max_pieces = max_welds + 1;
+    let n = offcuts.len();
+
+    let mut prefix = vec![0i64; n + 1];
+    for i in 0..n {
+        prefix[i + 1] = prefix[i] + offcuts[i] as i64;
+    }
+
+    let mut found: Vec<(i64, Vec<usize>)> = Vec::new();
+    let mut chosen: Vec<usize> = Vec::new();
+
+    collect_candidates(
+        offcuts,
+        &prefix,
+        target_length as i64,
+        0,
+        max_pieces,
+        0,
+        0,
+        weld_loss,
+        &mut chosen,
+        limit,
+        &mut found,
+    );
+
+    found
+}
+
+/// Depth-first step for `candidate_combinations`. Maintains `found` as a
+/// list sorted by ascending adjusted total, capped at `limit` entries.
+/// `accumulated` is already kerf/weld-gap adjusted; `pieces_used` tracks how
+/// many offcuts have been taken so far, since each one after the first
+/// forms a new weld.
+#[allow(clippy::too_many_arguments)]
+fn collect_candidates(
+    offcuts: &[usize],
+    prefix: &[i64],
+    target_length: i64,
+    start: usize,
+    pieces_left: usize,
+    pieces_used: usize,
+    accumulated: i64,
+    weld_loss: WeldLoss,
+    chosen: &mut Vec<usize>,
+    limit: usize,
+    found: &mut Vec<(i64, Vec<usize>)>,
+) {
+    if accumulated >= target_length {
+        let worst = found.last().map(|&(t, _)| t).unwrap_or(i64::MAX);
+        if found.len() < limit || accumulated < worst {
+            let pos = found.partition_point(|&(t, _)| t < accumulated);
+            found.insert(pos, (accumulated, chosen.clone()));
+            found.truncate(limit);
+        }
+        return;
+    }
+    if pieces_left == 0 || start >= offcuts.len() {
+        return;
+    }
+
+    let reach = (start + pieces_left).min(offcuts.len());
+    let take = reach - start;
+    let new_welds = if pieces_used == 0 {
+        take.saturating_sub(1)
+    } else {
+        take
+    };
+    let best_case = accumulated + (prefix[reach] - prefix[start]) - weld_loss.per_weld() * new_welds as i64;
+    if best_case < target_length {
+        return;
+    }
+    let worst = found.last().map(|&(t, _)| t).unwrap_or(i64::MAX);
+    if found.len() >= limit && accumulated >= worst {
+        return;
+    }
+
+    let delta = offcuts[start] as i64 - if pieces_used == 0 { 0 } else { weld_loss.per_weld() };
+
+    chosen.push(start);
+    collect_candidates(
+        offcuts,
+        prefix,
+        target_length,
+        start + 1,
+        pieces_left - 1,
+        pieces_used + 1,
+        accumulated + delta,
+        weld_loss,
+        chosen,
+        limit,
+        found,
+    );
+    chosen.pop();
+
+    collect_candidates(
+        offcuts,
+        prefix,
+        target_length,
+        start + 1,
+        pieces_left,
+        pieces_used,
+        accumulated,
+        weld_loss,
+        chosen,
+        limit,
+        found,
+    );
+}
+
+/// A node in the `pareto_front` search frontier: `next` is the next offcut
+/// index to consider, `pieces_used` offcuts have been taken so far summing,
+/// after kerf/weld-gap adjustment, to `total`, and `used_offcuts` records
+/// which ones.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct FrontierState {
+    total: i64,
+    pieces_used: usize,
+    next: usize,
+    used_offcuts: Vec<usize>,
+}
+
+impl Ord for FrontierState {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.total.cmp(&other.total)
+    }
+}
+
+impl PartialOrd for FrontierState {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Best-first search (a Dijkstra-style frontier over partial assembly
+/// states) that finds, for every achievable weld count `0..=max_welds`, the
+/// minimum-waste plan reaching `target_length` — the Pareto-optimal
+/// weld/waste front for this beam, sorted by ascending weld count.
+///
+/// States are popped off a min-heap in increasing accumulated-total order,
+/// so the first state for a given weld count that reaches `target_length`
+/// is that weld count's minimum-waste plan. Equivalent states (same next
+/// index, pieces used, and total) are deduplicated so equal-length offcuts
+/// don't blow up the frontier.
+fn pareto_front(
+    offcuts: &[usize],
+    target_length: usize,
+    max_welds: usize,
+    weld_loss: WeldLoss,
+) -> Vec<BeamPlan> {
+    let max_pieces = max_welds + 1;
+    let target = target_length as i64;
+    let mut heap: BinaryHeap<Reverse<FrontierState>> = BinaryHeap::new();
+    let mut visited: HashSet<(usize, usize, i64)> = HashSet::new();
+    let mut found_for = vec![false; max_pieces];
+    let mut front = Vec::new();
+
+    heap.push(Reverse(FrontierState {
+        total: 0,
+        pieces_used: 0,
+        next: 0,
+        used_offcuts: Vec::new(),
+    }));
+
+    while let Some(Reverse(state)) = heap.pop() {
+        if state.total >= target {
+            let welds = state.pieces_used - 1;
+            if !found_for[welds] {
+                found_for[welds] = true;
+                front.push(BeamPlan {
+                    // Guaranteed non-negative: total >= target >= 0 here.
+                    total: state.total as usize,
+                    welds,
+                    used_offcuts: state.used_offcuts,
+                    waste: (state.total - target) as usize,
+                });
+                if found_for.iter().all(|&f| f) {
+                    break;
+                }
+            }
+            // A complete state never benefits from taking more pieces.
+            continue;
+        }
+
+        if state.pieces_used >= max_pieces || state.next >= offcuts.len() {
+            continue;
+        }
+
+        // Skip offcuts[state.next].
+        if visited.insert((state.next + 1, state.pieces_used, state.total)) {
+            heap.push(Reverse(FrontierState {
+                total: state.total,
+                pieces_used: state.pieces_used,
+                next: state.next + 1,
+                used_offcuts: state.used_offcuts.clone(),
+            }));
+        }
+
+        // Take offcuts[state.next]. Every piece after the first forms a new
+        // weld, so it costs an extra `per_weld` on top of its raw length.
+        let delta = offcuts[state.next] as i64
+            - if state.pieces_used == 0 {
+                0
+            } else {
+                weld_loss.per_weld()
+            };
+        let total = state.total + delta;
+        if visited.insert((state.next + 1, state.pieces_used + 1, total)) {
+            let mut used_offcuts = state.used_offcuts.clone();
+            used_offcuts.push(offcuts[state.next]);
+            heap.push(Reverse(FrontierState {
+                total,
+                pieces_used: state.pieces_used + 1,
+                next: state.next + 1,
+                used_offcuts,
+            }));
+        }
+    }
+
+    front.sort_unstable_by_key(|plan| plan.welds);
+    front
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NO_LOSS: WeldLoss = WeldLoss {
+        kerf: 0,
+        weld_gap: 0,
+    };
+
+    #[test]
+    fn pareto_front_minimum_waste_plan_is_zero_waste_when_an_exact_offcut_exists() {
+        let offcuts = vec![100, 40, 35, 30];
+        let front = pareto_front(&offcuts, 100, 2, NO_LOSS);
+        let chosen = front.iter().min_by_key(|p| (p.waste, p.welds)).unwrap();
+        assert_eq!(chosen.waste, 0);
+        assert_eq!(chosen.welds, 0);
+        assert_eq!(chosen.used_offcuts, vec![100]);
+    }
+
+    #[test]
+    fn pareto_front_applies_kerf_and_weld_gap_per_weld() {
+        let offcuts = vec![700, 600, 400];
+        let weld_loss = WeldLoss {
+            kerf: 3,
+            weld_gap: 2,
+        };
+        let front = pareto_front(&offcuts, 1000, 1, weld_loss);
+        let one_weld = front.iter().find(|p| p.welds == 1).unwrap();
+        // Minimum-waste pair is 700 + 400, less one weld's worth of kerf +
+        // gap; 700 + 600 would reach target with more waste.
+        assert_eq!(one_weld.total, 700 + 400 - 5);
+        assert_eq!(one_weld.waste, one_weld.total - 1000);
+    }
+
+    #[test]
+    fn pareto_front_negative_weld_gap_adds_filler_instead_of_losing_length() {
+        let offcuts = vec![600, 400];
+        let weld_loss = WeldLoss {
+            kerf: 1,
+            weld_gap: -5,
+        };
+        let front = pareto_front(&offcuts, 1000, 1, weld_loss);
+        let one_weld = front.iter().find(|p| p.welds == 1).unwrap();
+        // Net per-weld loss is 1 + -5 = -4, i.e. a 4mm gain.
+        assert_eq!(one_weld.total, 600 + 400 + 4);
+    }
+
+    #[test]
+    fn candidate_combinations_adjusted_total_respects_kerf_and_weld_gap() {
+        let offcuts = vec![700, 600, 400];
+        let weld_loss = WeldLoss {
+            kerf: 3,
+            weld_gap: 2,
+        };
+        let candidates = candidate_combinations(&offcuts, 1000, 1, weld_loss, 3);
+        assert!(candidates
+            .iter()
+            .any(|&(total, ref indices)| total == 1295 && indices == &vec![0, 1]));
+    }
+
+    #[test]
+    fn greedy_plan_prefers_a_single_exact_fit_over_combining_pieces() {
+        let offcuts = vec![100, 40, 35, 30];
+        let plan = greedy_plan(&offcuts, 100, 2, NO_LOSS).unwrap();
+        assert_eq!(plan.welds, 0);
+        assert_eq!(plan.used_offcuts, vec![100]);
+    }
+
+    #[test]
+    fn greedy_plan_falls_back_to_combining_largest_remaining_pieces() {
+        let offcuts = vec![60, 50, 10];
+        let plan = greedy_plan(&offcuts, 100, 1, NO_LOSS).unwrap();
+        assert_eq!(plan.welds, 1);
+        assert_eq!(plan.used_offcuts, vec![60, 50]);
+        assert_eq!(plan.waste, 10);
+    }
+
+    #[test]
+    fn solve_global_solves_more_beams_than_greedy_per_beam_processing() {
+        // Beam 1 (100mm) has an exact single-offcut fit (the "100" piece,
+        // 0 waste) that a greedy per-beam pass will always prefer - but
+        // that leaves nothing for beam 2 (95mm), which can only be built
+        // from the "100" piece. Using the slightly wastier 90+15 combo for
+        // beam 1 instead frees the "100" piece for beam 2, solving both.
+        let requirements = vec![
+            BeamRequires {
+                size: 100,
+                welds: vec![1],
+            },
+            BeamRequires {
+                size: 95,
+                welds: vec![0],
+            },
+        ];
+        let offcuts = vec![100, 90, 15];
+
+        let per_beam = solve_per_beam(requirements.clone(), offcuts.clone(), NO_LOSS);
+        let per_beam_solved = per_beam.beams.iter().filter(|b| b.chosen.is_some()).count();
+        assert_eq!(per_beam_solved, 1);
+
+        let global = solve_global(&requirements, offcuts, NO_LOSS);
+        assert!(global.plans.iter().all(|p| p.is_some()));
+    }
+
+    #[test]
+    fn solve_global_does_not_undercount_beams_satisfiable_when_welding_adds_filler() {
+        // Raw offcut material (180) is less than the two beams' combined
+        // size (200), so a bound that ignores weld gain would wrongly
+        // conclude at most one beam is satisfiable. But each beam can be
+        // built from two 45mm pieces plus the 15mm gain per weld, so both
+        // are in fact achievable from exactly the four offcuts available.
+        let requirements = vec![
+            BeamRequires {
+                size: 100,
+                welds: vec![1],
+            },
+            BeamRequires {
+                size: 100,
+                welds: vec![1],
+            },
+        ];
+        let offcuts = vec![45, 45, 45, 45];
+        let weld_loss = WeldLoss {
+            kerf: 0,
+            weld_gap: -15,
+        };
+
+        let global = solve_global(&requirements, offcuts, weld_loss);
+        assert!(global.plans.iter().all(|p| p.is_some()));
+    }
+}